@@ -55,6 +55,50 @@ impl<'a> SliceString<'a> {
         Self::new_unchecked(SliceVec::from_slice_len(buf, len))
     }
 
+    /// Create a new `SliceString` from a mutable slice, appending `bytes`
+    /// with invalid UTF-8 sequences replaced by U+FFFD.
+    ///
+    /// See [`push_utf8_lossy`](Self::push_utf8_lossy) for the consumption
+    /// semantics: decoding stops cleanly once the backing slice is full.
+    pub fn from_utf8_lossy(buf: &'a mut [u8], bytes: &[u8]) -> Self {
+        let mut s = Self::new(buf);
+        s.push_utf8_lossy(bytes);
+        s
+    }
+
+    /// Decode a UTF-16 slice into a new `SliceString`.
+    ///
+    /// Surrogate pairs are handled transparently. Returns
+    /// [`FromUtf16Error::Unpaired`] on the first unpaired surrogate and
+    /// [`FromUtf16Error::Capacity`] if the decoded UTF-8 would not fit.
+    pub fn from_utf16(buf: &'a mut [u8], v: &[u16]) -> Result<Self, FromUtf16Error> {
+        let mut s = Self::new(buf);
+        for r in core::char::decode_utf16(v.iter().copied()) {
+            let c = r.map_err(|_| FromUtf16Error::Unpaired)?;
+            if s.capacity() < s.len() + c.len_utf8() {
+                return Err(FromUtf16Error::Capacity);
+            }
+            s.push(c);
+        }
+        Ok(s)
+    }
+
+    /// Decode a UTF-16 slice into a new `SliceString`, replacing unpaired
+    /// surrogates with U+FFFD.
+    ///
+    /// Decoding stops once the backing slice is full.
+    pub fn from_utf16_lossy(buf: &'a mut [u8], v: &[u16]) -> Self {
+        let mut s = Self::new(buf);
+        for r in core::char::decode_utf16(v.iter().copied()) {
+            let c = r.unwrap_or(core::char::REPLACEMENT_CHARACTER);
+            if s.capacity() < s.len() + c.len_utf8() {
+                break;
+            }
+            s.push(c);
+        }
+        s
+    }
+
     /// Return a mutable reference to the inner `SliceVec`.
     ///
     /// # Safety
@@ -103,23 +147,236 @@ impl<'a> SliceString<'a> {
         Some(ch)
     }
 
+    /// Append a `char` to the string if it fits.
+    ///
+    /// Returns [`CapacityError`] without modifying the string if the
+    /// remaining space is insufficient.
+    pub fn try_push(&mut self, c: char) -> Result<(), CapacityError> {
+        self.try_push_str(c.encode_utf8(&mut [0; 4]))
+    }
+
+    /// Append a `str` to the string if it fits.
+    ///
+    /// Returns [`CapacityError`] without modifying the string if the
+    /// remaining space is insufficient.
+    pub fn try_push_str(&mut self, string: &str) -> Result<(), CapacityError> {
+        if self.capacity() < self.len() + string.len() {
+            return Err(CapacityError);
+        }
+        self.0.extend_from_slice(string.as_bytes());
+        Ok(())
+    }
+
     /// Append a `char` to the string.
     ///
+    /// This delegates to [`try_push`](Self::try_push).
+    ///
     /// # Panics
     /// The remaining space must be sufficient.
     pub fn push(&mut self, c: char) {
-        match c.len_utf8() {
-            1 => self.0.push(c as u8),
-            _ => self.push_str(c.encode_utf8(&mut [0; 4])),
-        }
+        self.try_push(c).expect("SliceString is full")
     }
 
     /// Append a `str` to the string.
     ///
+    /// This delegates to [`try_push_str`](Self::try_push_str).
+    ///
     /// # Panics
     /// The remaining space must be sufficient.
     pub fn push_str(&mut self, string: &str) {
-        self.0.extend_from_slice(string.as_bytes())
+        self.try_push_str(string).expect("SliceString is full")
+    }
+
+    /// Insert a `char` at a byte position if it fits.
+    ///
+    /// Returns [`CapacityError`] without modifying the string if the
+    /// remaining space is insufficient.
+    ///
+    /// # Panics
+    /// `idx` must lie on a `char` boundary.
+    pub fn try_insert(&mut self, idx: usize, c: char) -> Result<(), CapacityError> {
+        self.try_insert_str(idx, c.encode_utf8(&mut [0; 4]))
+    }
+
+    /// Insert a `str` at a byte position if it fits.
+    ///
+    /// Returns [`CapacityError`] without modifying the string if the
+    /// remaining space is insufficient.
+    ///
+    /// # Panics
+    /// `idx` must lie on a `char` boundary.
+    pub fn try_insert_str(&mut self, idx: usize, string: &str) -> Result<(), CapacityError> {
+        assert!(self.is_char_boundary(idx));
+        let amt = string.len();
+        if self.capacity() < self.len() + amt {
+            return Err(CapacityError);
+        }
+        // Append the new bytes, then rotate them into the gap at `idx`.
+        let v = unsafe { self.as_mut_slicevec() };
+        v.extend_from_slice(string.as_bytes());
+        v[idx..].rotate_right(amt);
+        Ok(())
+    }
+
+    /// Insert a `char` at a byte position.
+    ///
+    /// This delegates to [`try_insert`](Self::try_insert).
+    ///
+    /// # Panics
+    /// `idx` must lie on a `char` boundary and the remaining space must be
+    /// sufficient.
+    pub fn insert(&mut self, idx: usize, c: char) {
+        self.try_insert(idx, c).expect("SliceString is full")
+    }
+
+    /// Insert a `str` at a byte position.
+    ///
+    /// This delegates to [`try_insert_str`](Self::try_insert_str).
+    ///
+    /// # Panics
+    /// `idx` must lie on a `char` boundary and the remaining space must be
+    /// sufficient.
+    pub fn insert_str(&mut self, idx: usize, string: &str) {
+        self.try_insert_str(idx, string).expect("SliceString is full")
+    }
+
+    /// Remove and return the `char` at a byte position.
+    ///
+    /// # Panics
+    /// `idx` must lie on a `char` boundary and be within the string.
+    pub fn remove(&mut self, idx: usize) -> char {
+        assert!(self.is_char_boundary(idx));
+        let ch = self[idx..].chars().next().expect("cannot remove past the end");
+        let amt = ch.len_utf8();
+        let v = unsafe { self.as_mut_slicevec() };
+        // Shift the tail left over the removed char, then shrink.
+        v[idx..].rotate_left(amt);
+        let new_len = v.len() - amt;
+        v.truncate(new_len);
+        ch
+    }
+
+    /// Append as much of `string` as fits, on a `char` boundary.
+    ///
+    /// Returns the number of bytes pushed.
+    fn push_str_fit(&mut self, string: &str) -> usize {
+        let mut n = (self.capacity() - self.len()).min(string.len());
+        while !string.is_char_boundary(n) {
+            n -= 1;
+        }
+        self.push_str(&string[..n]);
+        n
+    }
+
+    /// Append `bytes`, replacing invalid UTF-8 sequences with U+FFFD.
+    ///
+    /// Returns how many bytes of `bytes` were consumed. Because U+FFFD is
+    /// three bytes wide the output may grow faster than the input, so
+    /// decoding stops as soon as the remaining [`capacity`](Self::capacity)
+    /// is insufficient rather than overflowing the backing slice. This never
+    /// panics and is intended for decoding untrusted byte streams into a
+    /// fixed buffer.
+    pub fn push_utf8_lossy(&mut self, bytes: &[u8]) -> usize {
+        let mut consumed = 0;
+        while consumed < bytes.len() {
+            let remaining = &bytes[consumed..];
+            match str::from_utf8(remaining) {
+                Ok(valid) => {
+                    consumed += self.push_str_fit(valid);
+                    break;
+                }
+                Err(e) => {
+                    let valid_up_to = e.valid_up_to();
+                    // The prefix is valid UTF-8 by definition of `valid_up_to`.
+                    let valid = unsafe { str::from_utf8_unchecked(&remaining[..valid_up_to]) };
+                    let pushed = self.push_str_fit(valid);
+                    consumed += pushed;
+                    if pushed < valid_up_to {
+                        break;
+                    }
+                    match e.error_len() {
+                        Some(len) => {
+                            if self.capacity() < self.len() + '\u{FFFD}'.len_utf8() {
+                                break;
+                            }
+                            self.push('\u{FFFD}');
+                            consumed += len;
+                        }
+                        // An incomplete but possibly valid trailing sequence.
+                        None => break,
+                    }
+                }
+            }
+        }
+        consumed
+    }
+
+    /// Retain only the `char`s for which the predicate returns `true`.
+    ///
+    /// The `char`s are visited in order and the buffer is rewritten in a
+    /// single left-to-right pass with no temporary storage.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(char) -> bool,
+    {
+        let len = self.len();
+        let v = unsafe { self.as_mut_slicevec() };
+        let mut read = 0;
+        let mut write = 0;
+        while read < len {
+            // `v[read..len]` remains valid UTF-8 throughout the pass.
+            let c = unsafe { str::from_utf8_unchecked(&v[read..len]) }
+                .chars()
+                .next()
+                .unwrap();
+            let amt = c.len_utf8();
+            if f(c) {
+                if read != write {
+                    v.copy_within(read..read + amt, write);
+                }
+                write += amt;
+            }
+            read += amt;
+        }
+        v.truncate(write);
+    }
+
+    /// Remove a range of bytes and return an iterator over the removed `char`s.
+    ///
+    /// The remaining tail is shifted left to close the gap when the returned
+    /// [`Drain`] is dropped, even if it was only partially consumed.
+    ///
+    /// # Panics
+    /// Both ends of `range` must lie on `char` boundaries.
+    pub fn drain<R>(&mut self, range: R) -> Drain<'_, 'a>
+    where
+        R: ops::RangeBounds<usize>,
+    {
+        let len = self.len();
+        let start = match range.start_bound() {
+            ops::Bound::Included(&n) => n,
+            ops::Bound::Excluded(&n) => n + 1,
+            ops::Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            ops::Bound::Included(&n) => n + 1,
+            ops::Bound::Excluded(&n) => n,
+            ops::Bound::Unbounded => len,
+        };
+        assert!(self.is_char_boundary(start));
+        assert!(self.is_char_boundary(end));
+
+        // Borrow the drained bytes as `Chars`; the shift happens on drop.
+        let chars = unsafe {
+            let slice = core::slice::from_raw_parts(self.as_ptr().add(start), end - start);
+            str::from_utf8_unchecked(slice).chars()
+        };
+        Drain {
+            string: self as *mut _,
+            start,
+            end,
+            iter: chars,
+        }
     }
 
     /// Split the string and return the remainder.
@@ -139,6 +396,81 @@ impl<'a> SliceString<'a> {
     }
 }
 
+/// A draining iterator for [`SliceString`].
+///
+/// Created by [`SliceString::drain`]. The drained byte range is removed from
+/// the string when this iterator is dropped.
+pub struct Drain<'s, 'a> {
+    string: *mut SliceString<'a>,
+    start: usize,
+    end: usize,
+    iter: str::Chars<'s>,
+}
+
+impl Drop for Drain<'_, '_> {
+    fn drop(&mut self) {
+        let amt = self.end - self.start;
+        if amt == 0 {
+            return;
+        }
+        // Safety: the `Drain` holds the only access to the string for its
+        // lifetime, and `start`/`end` were validated char boundaries.
+        unsafe {
+            let v = (*self.string).as_mut_slicevec();
+            v[self.start..].rotate_left(amt);
+            let new_len = v.len() - amt;
+            v.truncate(new_len);
+        }
+    }
+}
+
+impl Iterator for Drain<'_, '_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for Drain<'_, '_> {
+    fn next_back(&mut self) -> Option<char> {
+        self.iter.next_back()
+    }
+}
+
+/// Error indicating the backing slice had insufficient free capacity.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CapacityError;
+
+impl fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("insufficient capacity")
+    }
+}
+
+/// Error returned by [`SliceString::from_utf16`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FromUtf16Error {
+    /// The backing slice did not have enough capacity for the decoded UTF-8.
+    Capacity,
+    /// The input contained an unpaired surrogate.
+    Unpaired,
+}
+
+impl fmt::Display for FromUtf16Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            Self::Capacity => "insufficient capacity",
+            Self::Unpaired => "unpaired surrogate in utf-16 input",
+        };
+        f.write_str(msg)
+    }
+}
+
 impl<'a> From<SliceString<'a>> for SliceVec<'a, u8> {
     fn from(value: SliceString<'a>) -> Self {
         value.0
@@ -213,19 +545,11 @@ impl<'a> AsRef<[u8]> for SliceString<'a> {
 
 impl<'a> fmt::Write for SliceString<'a> {
     fn write_str(&mut self, s: &str) -> Result<(), fmt::Error> {
-        if self.capacity() < self.len() + s.len() {
-            return Err(fmt::Error);
-        }
-        self.push_str(s);
-        Ok(())
+        self.try_push_str(s).map_err(|_| fmt::Error)
     }
 
     fn write_char(&mut self, c: char) -> Result<(), fmt::Error> {
-        if self.capacity() < self.len() + c.len_utf8() {
-            return Err(fmt::Error);
-        }
-        self.push(c);
-        Ok(())
+        self.try_push(c).map_err(|_| fmt::Error)
     }
 }
 
@@ -392,4 +716,128 @@ mod tests {
         s.push_str("ü");
         assert_eq!(s.as_str(), "öü");
     }
+
+    #[test]
+    fn utf8_lossy() {
+        let mut buf = [0u8; 16];
+        let s = SliceString::from_utf8_lossy(&mut buf[..], b"a\xffb");
+        assert_eq!(s.as_str(), "a\u{FFFD}b");
+
+        // An incomplete trailing sequence is left unconsumed.
+        let mut buf = [0u8; 16];
+        let mut s = SliceString::new(&mut buf[..]);
+        assert_eq!(s.push_utf8_lossy(b"ab\xe2\x82"), 2);
+        assert_eq!(s.as_str(), "ab");
+
+        // Decoding stops cleanly when capacity runs out, never panicking.
+        let mut buf = [0u8; 4];
+        let mut s = SliceString::new(&mut buf[..]);
+        assert_eq!(s.push_utf8_lossy(b"\xff\xff"), 1);
+        assert_eq!(s.as_str(), "\u{FFFD}");
+    }
+
+    #[test]
+    fn utf16() {
+        // 𝄞music, exercising a surrogate pair.
+        let v = [0xD834, 0xDD1E, 0x006D, 0x0075, 0x0073, 0x0069, 0x0063];
+        let mut buf = [0u8; 16];
+        let s = SliceString::from_utf16(&mut buf[..], &v).unwrap();
+        assert_eq!(s.as_str(), "𝄞music");
+
+        let v = [0x0061, 0xD800, 0x0062];
+        let mut buf = [0u8; 16];
+        assert_eq!(
+            SliceString::from_utf16(&mut buf[..], &v).unwrap_err(),
+            FromUtf16Error::Unpaired
+        );
+        let mut buf = [0u8; 16];
+        let s = SliceString::from_utf16_lossy(&mut buf[..], &v);
+        assert_eq!(s.as_str(), "a\u{FFFD}b");
+
+        let mut buf = [0u8; 2];
+        assert_eq!(
+            SliceString::from_utf16(&mut buf[..], &[0x0061, 0x0062, 0x0063]).unwrap_err(),
+            FromUtf16Error::Capacity
+        );
+    }
+
+    #[test]
+    fn fallible() {
+        let mut buf = [0u8; 4];
+        let mut s = SliceString::new(&mut buf[..]);
+        assert_eq!(s.try_push_str("fo"), Ok(()));
+        assert_eq!(s.try_push('o'), Ok(()));
+        assert_eq!(s.try_push('o'), Ok(()));
+        // Full: the string is left untouched on failure.
+        assert_eq!(s.try_push('!'), Err(CapacityError));
+        assert_eq!(s.try_push_str("!"), Err(CapacityError));
+        assert_eq!(s.as_str(), "fooo");
+
+        let mut buf = [0u8; 6];
+        let mut s = SliceString::new(&mut buf[..]);
+        s.push_str("foo");
+        assert_eq!(s.try_insert(1, 'x'), Ok(()));
+        assert_eq!(s.as_str(), "fxoo");
+        assert_eq!(s.try_insert_str(4, "yy"), Ok(()));
+        assert_eq!(s.as_str(), "fxooyy");
+        assert_eq!(s.try_insert(0, 'z'), Err(CapacityError));
+    }
+
+    #[test]
+    fn insert_remove() {
+        let mut buf = [0u8; 16];
+        let mut s = SliceString::new(&mut buf[..]);
+        s.push_str("héllo");
+        s.insert(0, 'x');
+        assert_eq!(s.as_str(), "xhéllo");
+        s.insert_str(1, "é");
+        assert_eq!(s.as_str(), "xéhéllo");
+
+        assert_eq!(s.remove(1), 'é');
+        assert_eq!(s.as_str(), "xhéllo");
+        assert_eq!(s.remove(0), 'x');
+        assert_eq!(s.as_str(), "héllo");
+        assert_eq!(s.remove(1), 'é');
+        assert_eq!(s.as_str(), "hllo");
+    }
+
+    #[test]
+    #[should_panic]
+    fn insert_not_boundary() {
+        let mut buf = [0u8; 16];
+        let mut s = SliceString::new(&mut buf[..]);
+        s.push_str("é");
+        s.insert(1, 'x');
+    }
+
+    #[test]
+    fn drain() {
+        let mut buf = [0u8; 16];
+        let mut s = SliceString::new(&mut buf[..]);
+        s.push_str("héllo wörld");
+
+        let drained: std::string::String = s.drain(3..10).collect();
+        assert_eq!(drained, "llo wö");
+        assert_eq!(s.as_str(), "hérld");
+
+        // Dropping a partially consumed iterator still closes the gap.
+        let mut it = s.drain(..1);
+        assert_eq!(it.next(), Some('h'));
+        drop(it);
+        assert_eq!(s.as_str(), "érld");
+    }
+
+    #[test]
+    fn retain() {
+        let mut buf = [0u8; 16];
+        let mut s = SliceString::new(&mut buf[..]);
+        s.push_str("héllo");
+        s.retain(|c| c != 'l');
+        assert_eq!(s.as_str(), "héo");
+
+        s.clear();
+        s.push_str("abcde");
+        s.retain(|c| (c as u32) % 2 == 1); // keep 'a', 'c', 'e'
+        assert_eq!(s.as_str(), "ace");
+    }
 }
@@ -5,12 +5,7 @@ impl uWrite for SliceString<'_> {
     type Error = ();
 
     fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
-        if self.capacity() < self.len() + s.len() {
-            return Err(());
-        }
-
-        self.push_str(s);
-        Ok(())
+        self.try_push_str(s).map_err(|_| ())
     }
 }
 